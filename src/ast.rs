@@ -0,0 +1,64 @@
+//! Abstract syntax tree produced by the parser.
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Int(i64),
+    Bool(bool),
+    Str(String),
+    Var(String),
+    Binary {
+        op: BinOp,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+    Call {
+        name: String,
+        args: Vec<Expr>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    Eq,
+    NotEq,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+}
+
+#[derive(Debug, Clone)]
+pub enum Statement {
+    VarAssign {
+        var: String,
+        value: Expr,
+    },
+    VarUpdate {
+        var: String,
+        value: Expr,
+    },
+    IfCondition {
+        cond: Expr,
+        true_branch: Vec<Statement>,
+        false_branch: Vec<Statement>,
+    },
+    WhileLoop {
+        cond: Expr,
+        body: Vec<Statement>,
+    },
+    FunctionCall {
+        name: String,
+        args: Vec<Expr>,
+    },
+    FunctionDef {
+        name: String,
+        params: Vec<String>,
+        body: Vec<Statement>,
+        ret: Option<Expr>,
+    },
+}
@@ -0,0 +1,107 @@
+//! Chained lexical-scope environment: each scope holds its own bindings
+//! plus an optional link to its parent scope, so a lookup walks outward
+//! until it finds a binding or runs out of parents. This is what lets
+//! blocks and function calls get their own local scope without losing
+//! access to variables declared further out.
+//!
+//! Generic over the bound type so the same scope-chain logic backs both
+//! the interpreter's runtime `Value` bindings and the checker's static
+//! `Type` bindings.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct Environment<T> {
+    values: HashMap<String, T>,
+    parent: Option<Rc<RefCell<Environment<T>>>>,
+}
+
+impl<T: Clone> Environment<T> {
+    pub fn new() -> Self {
+        Environment {
+            values: HashMap::new(),
+            parent: None,
+        }
+    }
+
+    pub fn child(parent: Rc<RefCell<Environment<T>>>) -> Self {
+        Environment {
+            values: HashMap::new(),
+            parent: Some(parent),
+        }
+    }
+
+    /// Declares `name` in this scope (`let`), shadowing any outer binding.
+    pub fn define(&mut self, name: String, value: T) {
+        self.values.insert(name, value);
+    }
+
+    pub fn get(&self, name: &str) -> Option<T> {
+        if let Some(value) = self.values.get(name) {
+            Some(value.clone())
+        } else if let Some(parent) = &self.parent {
+            parent.borrow().get(name)
+        } else {
+            None
+        }
+    }
+
+    /// Assigns to the nearest enclosing scope that already declares `name`,
+    /// defining it locally if no enclosing scope does.
+    pub fn set(&mut self, name: String, value: T) {
+        if !self.values.contains_key(&name) {
+            if let Some(parent) = &self.parent {
+                if parent.borrow().get(&name).is_some() {
+                    parent.borrow_mut().set(name, value);
+                    return;
+                }
+            }
+        }
+        self.values.insert(name, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn child_scope_sees_parent_bindings() {
+        let parent = Rc::new(RefCell::new(Environment::new()));
+        parent.borrow_mut().define("x".to_string(), 1);
+        let child = Environment::child(Rc::clone(&parent));
+        assert_eq!(child.get("x"), Some(1));
+    }
+
+    #[test]
+    fn define_in_child_shadows_without_leaking_to_parent() {
+        let parent = Rc::new(RefCell::new(Environment::new()));
+        parent.borrow_mut().define("x".to_string(), 1);
+        let child = Rc::new(RefCell::new(Environment::child(Rc::clone(&parent))));
+        child.borrow_mut().define("x".to_string(), 2);
+
+        assert_eq!(child.borrow().get("x"), Some(2));
+        assert_eq!(parent.borrow().get("x"), Some(1));
+    }
+
+    #[test]
+    fn set_assigns_to_the_nearest_enclosing_scope() {
+        let parent = Rc::new(RefCell::new(Environment::new()));
+        parent.borrow_mut().define("x".to_string(), 1);
+        let child = Rc::new(RefCell::new(Environment::child(Rc::clone(&parent))));
+
+        child.borrow_mut().set("x".to_string(), 9);
+
+        assert_eq!(child.borrow().get("x"), Some(9));
+        assert_eq!(parent.borrow().get("x"), Some(9));
+    }
+
+    #[test]
+    fn set_with_no_existing_binding_defines_locally() {
+        let env = Rc::new(RefCell::new(Environment::<i32>::new()));
+        env.borrow_mut().set("x".to_string(), 5);
+        assert_eq!(env.borrow().get("x"), Some(5));
+    }
+}
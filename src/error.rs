@@ -0,0 +1,109 @@
+//! Structured errors for the interpreter, with source spans for diagnostics.
+
+use thiserror::Error;
+
+/// A position in the source text, used to render caret-underlined
+/// diagnostics. 1-indexed, matching how editors report positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub col: usize,
+}
+
+#[derive(Debug, Clone, Error)]
+pub enum InterpretError {
+    #[error("unexpected character '{found}'")]
+    UnexpectedChar { found: char, span: Span },
+
+    #[error("expected {expected}, found {found}")]
+    UnexpectedToken {
+        expected: String,
+        found: String,
+        span: Span,
+    },
+
+    #[error("undefined variable `{name}`")]
+    UndefinedVariable { name: String },
+
+    #[error("unknown function `{name}`")]
+    UnknownFunction { name: String },
+
+    #[error("{message}")]
+    TypeError { message: String },
+
+    #[error("division by zero")]
+    DivisionByZero,
+
+    #[error("arithmetic overflow")]
+    Overflow,
+
+    #[error("invalid numeric literal `{text}`")]
+    InvalidNumber { text: String, span: Span },
+
+    #[error("unterminated string literal")]
+    UnterminatedString { span: Span },
+
+    #[error("io error: {0}")]
+    Io(String),
+}
+
+impl InterpretError {
+    fn span(&self) -> Option<Span> {
+        match self {
+            InterpretError::UnexpectedChar { span, .. } => Some(*span),
+            InterpretError::UnexpectedToken { span, .. } => Some(*span),
+            InterpretError::InvalidNumber { span, .. } => Some(*span),
+            InterpretError::UnterminatedString { span } => Some(*span),
+            _ => None,
+        }
+    }
+
+    /// Renders a caret-underlined snippet of the offending source line so
+    /// the user can see exactly where an error occurred. Falls back to a
+    /// plain message for errors that carry no span.
+    pub fn highlight(&self, source: &str) -> String {
+        match self.span() {
+            Some(span) => {
+                let line = source.lines().nth(span.line.saturating_sub(1)).unwrap_or("");
+                let caret = format!("{}^", " ".repeat(span.col.saturating_sub(1)));
+                format!(
+                    "error: {}\n  --> line {}:{}\n{}\n{}",
+                    self, span.line, span.col, line, caret
+                )
+            }
+            None => format!("error: {}", self),
+        }
+    }
+}
+
+impl From<std::io::Error> for InterpretError {
+    fn from(err: std::io::Error) -> Self {
+        InterpretError::Io(err.to_string())
+    }
+}
+
+pub type Result<T> = std::result::Result<T, InterpretError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlight_renders_a_caret_under_the_span() {
+        let err = InterpretError::UnexpectedChar {
+            found: '@',
+            span: Span { line: 2, col: 5 },
+        };
+        let rendered = err.highlight("let x = 1;\nlet y = @;\n");
+        assert!(rendered.contains("let y = @;"));
+        assert!(rendered.contains("    ^"));
+    }
+
+    #[test]
+    fn highlight_falls_back_to_a_plain_message_without_a_span() {
+        let err = InterpretError::UndefinedVariable {
+            name: "x".to_string(),
+        };
+        assert_eq!(err.highlight("let y = 1;"), "error: undefined variable `x`");
+    }
+}
@@ -0,0 +1,343 @@
+//! Tree-walking evaluator for the parsed `Statement`/`Expr` program.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::ast::{BinOp, Expr, Statement};
+use crate::environment::Environment;
+use crate::error::{InterpretError, Result};
+use crate::value::Value;
+
+#[derive(Debug, Clone)]
+struct FunctionInfo {
+    params: Vec<String>,
+    body: Vec<Statement>,
+    ret: Option<Expr>,
+}
+
+pub struct Interpreter {
+    // The outermost scope; functions close over this rather than whatever
+    // block happened to be calling them, so calls are lexically scoped.
+    global: Rc<RefCell<Environment<Value>>>,
+    env: Rc<RefCell<Environment<Value>>>,
+    functions: HashMap<String, FunctionInfo>,
+    script_args: Vec<String>,
+}
+
+impl Interpreter {
+    pub fn new() -> Self {
+        Self::with_args(Vec::new())
+    }
+
+    /// Like `new`, but makes `args` available to the script via `arg(i)`.
+    pub fn with_args(args: Vec<String>) -> Self {
+        let global = Rc::new(RefCell::new(Environment::new()));
+        Interpreter {
+            env: Rc::clone(&global),
+            global,
+            functions: HashMap::new(),
+            script_args: args,
+        }
+    }
+
+    pub fn evaluate(&mut self, statements: Vec<Statement>) -> Result<()> {
+        for statement in statements {
+            match statement {
+                Statement::VarAssign { var, value } => {
+                    let value = self.eval_expr(&value)?;
+                    self.env.borrow_mut().define(var, value);
+                }
+                Statement::VarUpdate { var, value } => {
+                    let value = self.eval_expr(&value)?;
+                    self.env.borrow_mut().set(var, value);
+                }
+                Statement::IfCondition {
+                    cond,
+                    true_branch,
+                    false_branch,
+                } => {
+                    if self.eval_expr(&cond)?.as_bool()? {
+                        self.exec_block(true_branch)?;
+                    } else {
+                        self.exec_block(false_branch)?;
+                    }
+                }
+                Statement::WhileLoop { cond, body } => {
+                    while self.eval_expr(&cond)?.as_bool()? {
+                        self.exec_block(body.clone())?;
+                    }
+                }
+                Statement::FunctionDef {
+                    name,
+                    params,
+                    body,
+                    ret,
+                } => {
+                    self.functions
+                        .insert(name, FunctionInfo { params, body, ret });
+                }
+                Statement::FunctionCall { name, args } => {
+                    self.eval_call(&name, &args)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs `body` in a fresh child scope, restoring the caller's scope
+    /// afterwards so locals declared inside don't leak out.
+    fn exec_block(&mut self, body: Vec<Statement>) -> Result<()> {
+        let parent = Rc::clone(&self.env);
+        self.env = Rc::new(RefCell::new(Environment::child(Rc::clone(&parent))));
+        let result = self.evaluate(body);
+        self.env = parent;
+        result
+    }
+
+    fn eval_expr(&mut self, expr: &Expr) -> Result<Value> {
+        match expr {
+            Expr::Int(value) => Ok(Value::Int(*value)),
+            Expr::Bool(value) => Ok(Value::Bool(*value)),
+            Expr::Str(value) => Ok(Value::Str(value.clone())),
+            Expr::Var(name) => self
+                .env
+                .borrow()
+                .get(name)
+                .ok_or_else(|| InterpretError::UndefinedVariable { name: name.clone() }),
+            Expr::Binary { op, left, right } => {
+                let l = self.eval_expr(left)?;
+                let r = self.eval_expr(right)?;
+                match op {
+                    BinOp::Add => {
+                        let (l, r) = (l.as_int()?, r.as_int()?);
+                        l.checked_add(r).map(Value::Int).ok_or(InterpretError::Overflow)
+                    }
+                    BinOp::Sub => {
+                        let (l, r) = (l.as_int()?, r.as_int()?);
+                        l.checked_sub(r).map(Value::Int).ok_or(InterpretError::Overflow)
+                    }
+                    BinOp::Mul => {
+                        let (l, r) = (l.as_int()?, r.as_int()?);
+                        l.checked_mul(r).map(Value::Int).ok_or(InterpretError::Overflow)
+                    }
+                    BinOp::Div => {
+                        let (l, r) = (l.as_int()?, r.as_int()?);
+                        if r == 0 {
+                            return Err(InterpretError::DivisionByZero);
+                        }
+                        l.checked_div(r).map(Value::Int).ok_or(InterpretError::Overflow)
+                    }
+                    BinOp::Mod => {
+                        let (l, r) = (l.as_int()?, r.as_int()?);
+                        if r == 0 {
+                            return Err(InterpretError::DivisionByZero);
+                        }
+                        l.checked_rem(r).map(Value::Int).ok_or(InterpretError::Overflow)
+                    }
+                    BinOp::Eq => Ok(Value::Bool(l == r)),
+                    BinOp::NotEq => Ok(Value::Bool(l != r)),
+                    BinOp::Lt => Ok(Value::Bool(l.as_int()? < r.as_int()?)),
+                    BinOp::Gt => Ok(Value::Bool(l.as_int()? > r.as_int()?)),
+                    BinOp::Le => Ok(Value::Bool(l.as_int()? <= r.as_int()?)),
+                    BinOp::Ge => Ok(Value::Bool(l.as_int()? >= r.as_int()?)),
+                }
+            }
+            Expr::Call { name, args } => self.eval_call(name, args),
+        }
+    }
+
+    fn eval_call(&mut self, name: &str, args: &[Expr]) -> Result<Value> {
+        match name {
+            "print" => {
+                for arg in args {
+                    let value = self.eval_expr(arg)?;
+                    print!("{} ", value);
+                }
+                println!();
+                Ok(Value::Int(0))
+            }
+            "arg" => {
+                if args.len() != 1 {
+                    return Err(InterpretError::TypeError {
+                        message: format!("function `arg` expects 1 argument(s), found {}", args.len()),
+                    });
+                }
+                let index = self.eval_expr(&args[0])?.as_int()?;
+                let index = usize::try_from(index).map_err(|_| InterpretError::TypeError {
+                    message: format!("script argument index must be non-negative, found {}", index),
+                })?;
+                self.script_args
+                    .get(index)
+                    .cloned()
+                    .map(Value::Str)
+                    .ok_or_else(|| InterpretError::TypeError {
+                        message: format!("no script argument at index {}", index),
+                    })
+            }
+            _ => {
+                let def = self
+                    .functions
+                    .get(name)
+                    .cloned()
+                    .ok_or_else(|| InterpretError::UnknownFunction {
+                        name: name.to_string(),
+                    })?;
+
+                if args.len() != def.params.len() {
+                    return Err(InterpretError::TypeError {
+                        message: format!(
+                            "function `{}` expects {} argument(s), found {}",
+                            name,
+                            def.params.len(),
+                            args.len()
+                        ),
+                    });
+                }
+
+                let mut arg_values = Vec::with_capacity(args.len());
+                for arg in args {
+                    arg_values.push(self.eval_expr(arg)?);
+                }
+
+                let call_env = Rc::new(RefCell::new(Environment::child(Rc::clone(&self.global))));
+                for (param, value) in def.params.iter().zip(arg_values) {
+                    call_env.borrow_mut().define(param.clone(), value);
+                }
+
+                let parent = std::mem::replace(&mut self.env, call_env);
+                let result = self.evaluate(def.body.clone()).and_then(|()| match &def.ret {
+                    Some(expr) => self.eval_expr(expr),
+                    None => Ok(Value::Int(0)),
+                });
+                self.env = parent;
+
+                result
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use crate::token::Lexer;
+
+    fn eval(src: &str) -> Interpreter {
+        let tokens = Lexer::new(src).tokenize().unwrap();
+        let statements = Parser::new(tokens).parse_program().unwrap();
+        let mut interpreter = Interpreter::new();
+        interpreter.evaluate(statements).unwrap();
+        interpreter
+    }
+
+    #[test]
+    fn arg_reads_script_arguments_by_index() {
+        let tokens = Lexer::new("let first = arg(0);").tokenize().unwrap();
+        let statements = Parser::new(tokens).parse_program().unwrap();
+        let mut interpreter = Interpreter::with_args(vec!["hello".to_string()]);
+        interpreter.evaluate(statements).unwrap();
+        assert_eq!(
+            interpreter.env.borrow().get("first"),
+            Some(Value::Str("hello".to_string()))
+        );
+    }
+
+    #[test]
+    fn arg_out_of_range_is_a_type_error() {
+        let tokens = Lexer::new("let first = arg(0);").tokenize().unwrap();
+        let statements = Parser::new(tokens).parse_program().unwrap();
+        let mut interpreter = Interpreter::with_args(Vec::new());
+        assert!(matches!(
+            interpreter.evaluate(statements),
+            Err(InterpretError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn function_params_are_local_to_the_call() {
+        let interpreter = eval(
+            r#"
+            let n = 1;
+            fn identity(n) {
+                return n;
+            }
+            let answer = identity(41);
+            "#,
+        );
+        assert_eq!(interpreter.env.borrow().get("n"), Some(Value::Int(1)));
+        assert_eq!(interpreter.env.borrow().get("answer"), Some(Value::Int(41)));
+    }
+
+    #[test]
+    fn function_with_a_loop_accumulates_a_result() {
+        let interpreter = eval(
+            r#"
+            fn factorial(n) {
+                let result = 1;
+                let i = n;
+                while i > 1 {
+                    result = result * i;
+                    i = i - 1;
+                }
+                return result;
+            }
+            let answer = factorial(5);
+            "#,
+        );
+        assert_eq!(interpreter.env.borrow().get("answer"), Some(Value::Int(120)));
+    }
+
+    #[test]
+    fn calling_with_too_few_arguments_is_a_type_error() {
+        let tokens = Lexer::new("fn add(a, b) { return a + b; } add(1);")
+            .tokenize()
+            .unwrap();
+        let statements = Parser::new(tokens).parse_program().unwrap();
+        let mut interpreter = Interpreter::new();
+        assert!(matches!(
+            interpreter.evaluate(statements),
+            Err(InterpretError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn addition_overflow_is_an_overflow_error_not_a_panic() {
+        let tokens = Lexer::new("let x = 9223372036854775807; let y = x + 1;")
+            .tokenize()
+            .unwrap();
+        let statements = Parser::new(tokens).parse_program().unwrap();
+        let mut interpreter = Interpreter::new();
+        assert!(matches!(
+            interpreter.evaluate(statements),
+            Err(InterpretError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn division_overflow_is_an_overflow_error() {
+        let tokens = Lexer::new("let x = 0 - 9223372036854775807 - 1; let y = x / -1;")
+            .tokenize()
+            .unwrap();
+        let statements = Parser::new(tokens).parse_program().unwrap();
+        let mut interpreter = Interpreter::new();
+        assert!(matches!(
+            interpreter.evaluate(statements),
+            Err(InterpretError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn calling_with_too_many_arguments_is_a_type_error() {
+        let tokens = Lexer::new("fn add(a, b) { return a + b; } add(1, 2, 3);")
+            .tokenize()
+            .unwrap();
+        let statements = Parser::new(tokens).parse_program().unwrap();
+        let mut interpreter = Interpreter::new();
+        assert!(matches!(
+            interpreter.evaluate(statements),
+            Err(InterpretError::TypeError { .. })
+        ));
+    }
+}
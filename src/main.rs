@@ -1,226 +1,171 @@
-use regex::Regex;
-use std::collections::HashMap;
-
-#[derive(Debug, Clone)]
-enum Statement {
-    VarAssign { var: String, value: i64 },
-    VarUpdate { var: String, value: i64 },
-    IfCondition {
-        var: String,
-        value: i64,
-        true_branch: Vec<Statement>,
-        false_branch: Vec<Statement>,
-    },
-    WhileLoop {
-        var: String,
-        op: String,
-        value: i64,
-        body: Vec<Statement>,
-    },
-    FunctionCall { name: String, args: Vec<i64> },
+mod ast;
+mod environment;
+mod error;
+mod interpreter;
+mod parser;
+mod token;
+mod typecheck;
+mod value;
+
+use std::fs;
+use std::io::{self, Write};
+
+use clap::{Parser as ClapParser, Subcommand};
+
+use error::Result;
+use interpreter::Interpreter;
+use parser::Parser;
+use token::Lexer;
+use typecheck::Checker;
+
+#[derive(ClapParser)]
+#[command(name = "sci", about = "Smart Contract Interpreter")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-struct Interpreter {
-    variables: HashMap<String, i64>,
+#[derive(Subcommand)]
+enum Command {
+    /// Parse and execute a `.sci` source file. Any trailing arguments are
+    /// passed through to the script, readable via `arg(i)`.
+    Run {
+        file: String,
+        #[arg(trailing_var_arg = true)]
+        args: Vec<String>,
+    },
+    /// Parse and type-check a `.sci` source file without executing it.
+    Check { file: String },
 }
 
-impl Interpreter {
-    fn new() -> Self {
-        Interpreter {
-            variables: HashMap::new(),
-        }
-    }
+fn main() {
+    let cli = Cli::parse();
 
-    fn parse(&self, code: &str) -> Vec<Statement> {
-        let mut statements = Vec::new();
-        let re_assign = Regex::new(r"^let (\w+) = (-?\d+);$").unwrap();
-        let re_update = Regex::new(r"^(\w+) = (\w+) \+ (-?\d+);$").unwrap();
-        let re_if = Regex::new(r"^if (\w+) == (-?\d+) \{$").unwrap();
-        let re_else = Regex::new(r"^\} else \{$").unwrap();
-        let re_endif = Regex::new(r"^\}$").unwrap();
-        let re_while = Regex::new(r"^while (\w+) (==|!=|<|>|<=|>=) (-?\d+) \{$").unwrap();
-        let re_endwhile = Regex::new(r"^\}$").unwrap();
-        let re_function_call = Regex::new(r"^(\w+)\(([^)]*)\);$").unwrap();
-
-        let lines: Vec<&str> = code.lines().collect();
-        let mut i = 0;
-
-        while i < lines.len() {
-            let line = lines[i].trim();
-            if let Some(caps) = re_assign.captures(line) {
-                let var = caps[1].to_string();
-                let value = caps[2].parse::<i64>().unwrap();
-                statements.push(Statement::VarAssign { var, value });
-            } else if let Some(caps) = re_update.captures(line) {
-                let var = caps[1].to_string();
-                let value = caps[3].parse::<i64>().unwrap();
-                statements.push(Statement::VarUpdate { var, value });
-            } else if let Some(caps) = re_if.captures(line) {
-                let var = caps[1].to_string();
-                let value = caps[2].parse::<i64>().unwrap();
-                let mut true_branch = Vec::new();
-                let mut false_branch = Vec::new();
-
-                i += 1;
-                while i < lines.len() && !re_else.is_match(lines[i].trim()) && !re_endif.is_match(lines[i].trim()) {
-                    true_branch.push(self.parse_statement(lines[i].trim()));
-                    i += 1;
-                }
-
-                if i < lines.len() && re_else.is_match(lines[i].trim()) {
-                    i += 1;
-                    while i < lines.len() && !re_endif.is_match(lines[i].trim()) {
-                        false_branch.push(self.parse_statement(lines[i].trim()));
-                        i += 1;
-                    }
-                }
-
-                statements.push(Statement::IfCondition {
-                    var,
-                    value,
-                    true_branch,
-                    false_branch,
-                });
-            } else if let Some(caps) = re_while.captures(line) {
-                let var = caps[1].to_string();
-                let op = caps[2].to_string();
-                let value = caps[3].parse::<i64>().unwrap();
-                let mut body = Vec::new();
-
-                i += 1;
-                while i < lines.len() && !re_endwhile.is_match(lines[i].trim()) {
-                    body.push(self.parse_statement(lines[i].trim()));
-                    i += 1;
-                }
-
-                statements.push(Statement::WhileLoop { var, op, value, body });
-            } else if let Some(caps) = re_function_call.captures(line) {
-                let name = caps[1].to_string();
-                let args: Vec<i64> = caps[2]
-                    .split(',')
-                    .map(|arg| arg.trim().parse().unwrap())
-                    .collect();
-                statements.push(Statement::FunctionCall { name, args });
-            }
-
-            i += 1;
-        }
+    let ok = match cli.command {
+        Some(Command::Run { file, args }) => run_file(&file, args),
+        Some(Command::Check { file }) => check_file(&file),
+        None => repl().is_ok(),
+    };
 
-        statements
+    if !ok {
+        std::process::exit(1);
     }
+}
 
-    fn parse_statement(&self, line: &str) -> Statement {
-        let re_assign = Regex::new(r"^let (\w+) = (-?\d+);$").unwrap();
-        let re_update = Regex::new(r"^(\w+) = (\w+) \+ (-?\d+);$").unwrap();
-        let re_function_call = Regex::new(r"^(\w+)\(([^)]*)\);$").unwrap();
-
-        if let Some(caps) = re_assign.captures(line) {
-            let var = caps[1].to_string();
-            let value = caps[2].parse::<i64>().unwrap();
-            Statement::VarAssign { var, value }
-        } else if let Some(caps) = re_update.captures(line) {
-            let var = caps[1].to_string();
-            let value = caps[3].parse::<i64>().unwrap();
-            Statement::VarUpdate { var, value }
-        } else if let Some(caps) = re_function_call.captures(line) {
-            let name = caps[1].to_string();
-            let args: Vec<i64> = caps[2]
-                .split(',')
-                .map(|arg| arg.trim().parse().unwrap())
-                .collect();
-            Statement::FunctionCall { name, args }
-        } else {
-            panic!("Invalid statement: {}", line);
-        }
-    }
+/// Reads `path`, reporting an unadorned message if it can't be read at all
+/// (there's no source to highlight a span against yet).
+fn read_source(path: &str) -> std::result::Result<String, ()> {
+    fs::read_to_string(path).map_err(|err| eprintln!("error: {}", err))
+}
 
-    fn evaluate(&mut self, statements: Vec<Statement>) {
-        for statement in statements {
-            match statement {
-                Statement::VarAssign { var, value } => {
-                    self.variables.insert(var, value);
-                }
-                Statement::VarUpdate { var, value } => {
-                    if let Some(var_value) = self.variables.get_mut(&var) {
-                        *var_value += value;
-                    }
-                }
-                Statement::IfCondition {
-                    var,
-                    value,
-                    true_branch,
-                    false_branch,
-                } => {
-                    if let Some(var_value) = self.variables.get(&var) {
-                        if *var_value == value {
-                            self.evaluate(true_branch);
-                        } else {
-                            self.evaluate(false_branch);
-                        }
-                    }
-                }
-                Statement::WhileLoop { var, op, value, body } => {
-                    while self.evaluate_condition(&var, &op, value) {
-                        self.evaluate(body.clone());
-                    }
-                }
-                Statement::FunctionCall { name, args } => match name.as_str() {
-                    "print" => {
-                        for arg in args {
-                            print!("{} ", arg);
-                        }
-                        println!();
-                    }
-                    _ => panic!("Unknown function: {}", name),
-                },
-            }
+fn run_file(path: &str, args: Vec<String>) -> bool {
+    let Ok(source) = read_source(path) else {
+        return false;
+    };
+    match run_source(&source, args) {
+        Ok(()) => true,
+        Err(err) => {
+            eprintln!("{}", err.highlight(&source));
+            false
         }
     }
+}
 
-    fn evaluate_condition(&self, var: &String, op: &String, value: i64) -> bool {
-        if let Some(var_value) = self.variables.get(var) {
-            match op.as_str() {
-                "==" => *var_value == value,
-                "!=" => *var_value != value,
-                "<" => *var_value < value,
-                ">" => *var_value > value,
-                "<=" => *var_value <= value,
-                ">=" => *var_value >= value,
-                _ => false,
-            }
-        } else {
+fn check_file(path: &str) -> bool {
+    let Ok(source) = read_source(path) else {
+        return false;
+    };
+    let result = Lexer::new(&source)
+        .tokenize()
+        .and_then(|tokens| Parser::new(tokens).parse_program())
+        .and_then(|statements| Checker::new().check(&statements));
+
+    match result {
+        Ok(_) => {
+            println!("{}: OK", path);
+            true
+        }
+        Err(err) => {
+            eprintln!("{}", err.highlight(&source));
             false
         }
     }
 }
 
-fn main() {
+fn run_source(source: &str, args: Vec<String>) -> Result<()> {
+    eval_into(&mut Interpreter::with_args(args), source)
+}
+
+fn eval_into(interpreter: &mut Interpreter, source: &str) -> Result<()> {
+    let tokens = Lexer::new(source).tokenize()?;
+    let statements = Parser::new(tokens).parse_program()?;
+    interpreter.evaluate(statements)
+}
+
+/// Interactive REPL: keeps one `Interpreter` across lines and buffers input
+/// until braces balance and the buffered statement is terminated, showing a
+/// continuation prompt while a multi-line `if`/`while`/`fn` is still open.
+fn repl() -> Result<()> {
     let mut interpreter = Interpreter::new();
+    let stdin = io::stdin();
+    let mut buffer = String::new();
+
+    loop {
+        print!("{}", if buffer.is_empty() { "sci> " } else { "...  " });
+        io::stdout().flush().ok();
 
-    let code = r#"
-        let x = 10;
-        let y = 20;
-        x = x + 5;
-        if x == 15 {
-            print(1, 2, 3);
-        } else {
-            print(4, 5, 6);
+        let mut line = String::new();
+        if stdin.read_line(&mut line)? == 0 {
+            break; // EOF
         }
-    "#;
+        buffer.push_str(&line);
 
-    let statements = interpreter.parse(code);
-    interpreter.evaluate(statements);
+        if !is_complete(&buffer) {
+            continue;
+        }
 
-    let mut interpreter = Interpreter::new();
+        let source = std::mem::take(&mut buffer);
+        if let Err(err) = eval_into(&mut interpreter, &source) {
+            eprintln!("{}", err.highlight(&source));
+        }
+    }
+
+    Ok(())
+}
 
-    let code = r#"
-        let x = 0;
-        while x < 5 {
-            x = x + 1;
-            print(x);
+fn is_complete(buffer: &str) -> bool {
+    let mut depth: i32 = 0;
+    for c in buffer.chars() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            _ => {}
         }
-    "#;
+    }
+    depth <= 0 && buffer.trim_end().ends_with([';', '}'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let statements = interpreter.parse(code);
-    interpreter.evaluate(statements);
+    #[test]
+    fn single_line_statement_is_complete() {
+        assert!(is_complete("let x = 1;\n"));
+    }
+
+    #[test]
+    fn statement_missing_its_semicolon_is_incomplete() {
+        assert!(!is_complete("let x = 1\n"));
+    }
+
+    #[test]
+    fn open_brace_is_incomplete() {
+        assert!(!is_complete("if x == 1 {\n"));
+    }
+
+    #[test]
+    fn balanced_braces_across_lines_is_complete() {
+        assert!(is_complete("if x == 1 {\nprint(x);\n}\n"));
+    }
 }
@@ -0,0 +1,340 @@
+//! Recursive-descent parser with a Pratt expression parser for operator
+//! precedence. Turns a flat token stream into the `Statement`/`Expr` tree.
+
+use crate::ast::{BinOp, Expr, Statement};
+use crate::error::{InterpretError, Result, Span};
+use crate::token::Token;
+
+pub struct Parser {
+    tokens: Vec<(Token, Span)>,
+    pos: usize,
+}
+
+impl Parser {
+    pub fn new(tokens: Vec<(Token, Span)>) -> Self {
+        Parser { tokens, pos: 0 }
+    }
+
+    pub fn parse_program(&mut self) -> Result<Vec<Statement>> {
+        let mut statements = Vec::new();
+        while !self.check(&Token::Eof) {
+            statements.push(self.parse_statement()?);
+        }
+        Ok(statements)
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos].0
+    }
+
+    fn span(&self) -> Span {
+        self.tokens[self.pos].1
+    }
+
+    fn check(&self, token: &Token) -> bool {
+        self.peek() == token
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.pos].0.clone();
+        if self.pos < self.tokens.len() - 1 {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn expect(&mut self, token: &Token) -> Result<()> {
+        if !self.check(token) {
+            return Err(InterpretError::UnexpectedToken {
+                expected: format!("{:?}", token),
+                found: format!("{:?}", self.peek()),
+                span: self.span(),
+            });
+        }
+        self.advance();
+        Ok(())
+    }
+
+    fn expect_ident(&mut self) -> Result<String> {
+        let span = self.span();
+        match self.advance() {
+            Token::Ident(name) => Ok(name),
+            other => Err(InterpretError::UnexpectedToken {
+                expected: "an identifier".to_string(),
+                found: format!("{:?}", other),
+                span,
+            }),
+        }
+    }
+
+    fn parse_statement(&mut self) -> Result<Statement> {
+        match self.peek() {
+            Token::Let => self.parse_let(),
+            Token::If => self.parse_if(),
+            Token::While => self.parse_while(),
+            Token::Fn => self.parse_fn(),
+            Token::Ident(_) => self.parse_ident_statement(),
+            other => Err(InterpretError::UnexpectedToken {
+                expected: "a statement".to_string(),
+                found: format!("{:?}", other.clone()),
+                span: self.span(),
+            }),
+        }
+    }
+
+    fn parse_let(&mut self) -> Result<Statement> {
+        self.expect(&Token::Let)?;
+        let var = self.expect_ident()?;
+        self.expect(&Token::Eq)?;
+        let value = self.parse_expr(0)?;
+        self.expect(&Token::Semicolon)?;
+        Ok(Statement::VarAssign { var, value })
+    }
+
+    fn parse_if(&mut self) -> Result<Statement> {
+        self.expect(&Token::If)?;
+        let cond = self.parse_expr(0)?;
+        let true_branch = self.parse_block()?;
+        let false_branch = if self.check(&Token::Else) {
+            self.advance();
+            self.parse_block()?
+        } else {
+            Vec::new()
+        };
+        Ok(Statement::IfCondition {
+            cond,
+            true_branch,
+            false_branch,
+        })
+    }
+
+    fn parse_while(&mut self) -> Result<Statement> {
+        self.expect(&Token::While)?;
+        let cond = self.parse_expr(0)?;
+        let body = self.parse_block()?;
+        Ok(Statement::WhileLoop { cond, body })
+    }
+
+    /// Parses `fn name(params) { body return expr; }`. The trailing
+    /// `return` is not a general statement here: it always ends the body
+    /// and becomes the function's `ret` expression.
+    fn parse_fn(&mut self) -> Result<Statement> {
+        self.expect(&Token::Fn)?;
+        let name = self.expect_ident()?;
+        self.expect(&Token::LParen)?;
+        let mut params = Vec::new();
+        if !self.check(&Token::RParen) {
+            params.push(self.expect_ident()?);
+            while self.check(&Token::Comma) {
+                self.advance();
+                params.push(self.expect_ident()?);
+            }
+        }
+        self.expect(&Token::RParen)?;
+        self.expect(&Token::LBrace)?;
+
+        let mut body = Vec::new();
+        let mut ret = None;
+        while !self.check(&Token::RBrace) {
+            if self.check(&Token::Return) {
+                self.advance();
+                ret = Some(self.parse_expr(0)?);
+                self.expect(&Token::Semicolon)?;
+                break;
+            }
+            body.push(self.parse_statement()?);
+        }
+        self.expect(&Token::RBrace)?;
+
+        Ok(Statement::FunctionDef {
+            name,
+            params,
+            body,
+            ret,
+        })
+    }
+
+    /// Parses a `{ ... }` block by matching braces directly off the token
+    /// stream, rather than hand-counting braces line by line.
+    fn parse_block(&mut self) -> Result<Vec<Statement>> {
+        self.expect(&Token::LBrace)?;
+        let mut statements = Vec::new();
+        while !self.check(&Token::RBrace) {
+            statements.push(self.parse_statement()?);
+        }
+        self.expect(&Token::RBrace)?;
+        Ok(statements)
+    }
+
+    fn parse_ident_statement(&mut self) -> Result<Statement> {
+        let name = self.expect_ident()?;
+        match self.peek() {
+            Token::Eq => {
+                self.advance();
+                let value = self.parse_expr(0)?;
+                self.expect(&Token::Semicolon)?;
+                Ok(Statement::VarUpdate { var: name, value })
+            }
+            Token::LParen => {
+                let args = self.parse_args()?;
+                self.expect(&Token::Semicolon)?;
+                Ok(Statement::FunctionCall { name, args })
+            }
+            other => Err(InterpretError::UnexpectedToken {
+                expected: "`=` or `(`".to_string(),
+                found: format!("{:?}", other.clone()),
+                span: self.span(),
+            }),
+        }
+    }
+
+    fn parse_args(&mut self) -> Result<Vec<Expr>> {
+        self.expect(&Token::LParen)?;
+        let mut args = Vec::new();
+        if !self.check(&Token::RParen) {
+            args.push(self.parse_expr(0)?);
+            while self.check(&Token::Comma) {
+                self.advance();
+                args.push(self.parse_expr(0)?);
+            }
+        }
+        self.expect(&Token::RParen)?;
+        Ok(args)
+    }
+
+    /// Pratt parser: binding powers are spaced two apart so left/right
+    /// associativity can be expressed without fractional precedence levels.
+    fn parse_expr(&mut self, min_bp: u8) -> Result<Expr> {
+        let mut left = self.parse_primary()?;
+
+        loop {
+            let op = match self.peek() {
+                Token::Plus => BinOp::Add,
+                Token::Minus => BinOp::Sub,
+                Token::Star => BinOp::Mul,
+                Token::Slash => BinOp::Div,
+                Token::Percent => BinOp::Mod,
+                Token::EqEq => BinOp::Eq,
+                Token::NotEq => BinOp::NotEq,
+                Token::Lt => BinOp::Lt,
+                Token::Gt => BinOp::Gt,
+                Token::Le => BinOp::Le,
+                Token::Ge => BinOp::Ge,
+                _ => break,
+            };
+
+            let (l_bp, r_bp) = binding_power(op);
+            if l_bp < min_bp {
+                break;
+            }
+            self.advance();
+            let right = self.parse_expr(r_bp)?;
+            left = Expr::Binary {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+
+        Ok(left)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr> {
+        let span = self.span();
+        match self.advance() {
+            Token::Int(value) => Ok(Expr::Int(value)),
+            Token::Str(value) => Ok(Expr::Str(value)),
+            Token::True => Ok(Expr::Bool(true)),
+            Token::False => Ok(Expr::Bool(false)),
+            Token::Minus => {
+                // Unary minus folds into `0 - expr` instead of growing a
+                // separate AST node for a single-use operator.
+                let operand = self.parse_expr(UNARY_BP)?;
+                Ok(Expr::Binary {
+                    op: BinOp::Sub,
+                    left: Box::new(Expr::Int(0)),
+                    right: Box::new(operand),
+                })
+            }
+            Token::Ident(name) => {
+                if self.check(&Token::LParen) {
+                    let args = self.parse_args()?;
+                    Ok(Expr::Call { name, args })
+                } else {
+                    Ok(Expr::Var(name))
+                }
+            }
+            Token::LParen => {
+                let expr = self.parse_expr(0)?;
+                self.expect(&Token::RParen)?;
+                Ok(expr)
+            }
+            other => Err(InterpretError::UnexpectedToken {
+                expected: "an expression".to_string(),
+                found: format!("{:?}", other),
+                span,
+            }),
+        }
+    }
+}
+
+const UNARY_BP: u8 = 9;
+
+fn binding_power(op: BinOp) -> (u8, u8) {
+    match op {
+        BinOp::Eq | BinOp::NotEq | BinOp::Lt | BinOp::Gt | BinOp::Le | BinOp::Ge => (1, 2),
+        BinOp::Add | BinOp::Sub => (3, 4),
+        BinOp::Mul | BinOp::Div | BinOp::Mod => (5, 6),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token::Lexer;
+
+    fn parse_expr_str(src: &str) -> Expr {
+        let tokens = Lexer::new(src).tokenize().unwrap();
+        Parser::new(tokens).parse_expr(0).unwrap()
+    }
+
+    #[test]
+    fn multiplication_binds_tighter_than_addition() {
+        let expr = parse_expr_str("1 + 2 * 3");
+        match expr {
+            Expr::Binary { op: BinOp::Add, left, right } => {
+                assert!(matches!(*left, Expr::Int(1)));
+                assert!(matches!(*right, Expr::Binary { op: BinOp::Mul, .. }));
+            }
+            other => panic!("expected a top-level Add, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parentheses_override_precedence() {
+        let expr = parse_expr_str("(1 + 2) * 3");
+        match expr {
+            Expr::Binary { op: BinOp::Mul, left, .. } => {
+                assert!(matches!(*left, Expr::Binary { op: BinOp::Add, .. }));
+            }
+            other => panic!("expected a top-level Mul, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn comparison_binds_looser_than_arithmetic() {
+        let expr = parse_expr_str("1 + 2 == 3");
+        assert!(matches!(expr, Expr::Binary { op: BinOp::Eq, .. }));
+    }
+
+    #[test]
+    fn unary_minus_binds_tighter_than_multiplication() {
+        let expr = parse_expr_str("-2 * 3");
+        match expr {
+            Expr::Binary { op: BinOp::Mul, left, .. } => {
+                assert!(matches!(*left, Expr::Binary { op: BinOp::Sub, .. }));
+            }
+            other => panic!("expected a top-level Mul, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,299 @@
+//! Tokenizer for Smart Contract Interpreter source. Tracks line/column
+//! offsets so parse errors can point at the offending span.
+
+use crate::error::{InterpretError, Result, Span};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Let,
+    If,
+    Else,
+    While,
+    Fn,
+    Return,
+    Ident(String),
+    Int(i64),
+    Str(String),
+    True,
+    False,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    EqEq,
+    NotEq,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+    Eq,
+    LBrace,
+    RBrace,
+    LParen,
+    RParen,
+    Comma,
+    Semicolon,
+    Eof,
+}
+
+pub struct Lexer<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+    col: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Lexer {
+            chars: source.chars().peekable(),
+            line: 1,
+            col: 1,
+        }
+    }
+
+    pub fn tokenize(mut self) -> Result<Vec<(Token, Span)>> {
+        let mut tokens = Vec::new();
+        loop {
+            let (token, span) = self.next_token()?;
+            let done = token == Token::Eof;
+            tokens.push((token, span));
+            if done {
+                break;
+            }
+        }
+        Ok(tokens)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        if c == '\n' {
+            self.line += 1;
+            self.col = 1;
+        } else {
+            self.col += 1;
+        }
+        Some(c)
+    }
+
+    fn span(&self) -> Span {
+        Span {
+            line: self.line,
+            col: self.col,
+        }
+    }
+
+    fn next_token(&mut self) -> Result<(Token, Span)> {
+        self.skip_whitespace();
+        let span = self.span();
+
+        let c = match self.chars.peek() {
+            Some(&c) => c,
+            None => return Ok((Token::Eof, span)),
+        };
+
+        if c.is_ascii_digit() {
+            return self.read_number(span);
+        }
+        if c.is_alphabetic() || c == '_' {
+            return Ok((self.read_ident_or_keyword(), span));
+        }
+        if c == '"' {
+            return Ok((self.read_string(span)?, span));
+        }
+
+        self.bump();
+        let token = match c {
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '%' => Token::Percent,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '{' => Token::LBrace,
+            '}' => Token::RBrace,
+            ',' => Token::Comma,
+            ';' => Token::Semicolon,
+            '=' => {
+                if self.chars.peek() == Some(&'=') {
+                    self.bump();
+                    Token::EqEq
+                } else {
+                    Token::Eq
+                }
+            }
+            '!' => {
+                if self.chars.peek() == Some(&'=') {
+                    self.bump();
+                    Token::NotEq
+                } else {
+                    return Err(InterpretError::UnexpectedChar { found: c, span });
+                }
+            }
+            '<' => {
+                if self.chars.peek() == Some(&'=') {
+                    self.bump();
+                    Token::Le
+                } else {
+                    Token::Lt
+                }
+            }
+            '>' => {
+                if self.chars.peek() == Some(&'=') {
+                    self.bump();
+                    Token::Ge
+                } else {
+                    Token::Gt
+                }
+            }
+            other => return Err(InterpretError::UnexpectedChar { found: other, span }),
+        };
+        Ok((token, span))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(&c) = self.chars.peek() {
+            if c.is_whitespace() {
+                self.bump();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn read_number(&mut self, span: Span) -> Result<(Token, Span)> {
+        let mut number = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c.is_ascii_digit() {
+                number.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        let value = number
+            .parse()
+            .map_err(|_| InterpretError::InvalidNumber { text: number, span })?;
+        Ok((Token::Int(value), span))
+    }
+
+    fn read_ident_or_keyword(&mut self) -> Token {
+        let mut ident = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c.is_alphanumeric() || c == '_' {
+                ident.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        match ident.as_str() {
+            "let" => Token::Let,
+            "if" => Token::If,
+            "else" => Token::Else,
+            "while" => Token::While,
+            "fn" => Token::Fn,
+            "return" => Token::Return,
+            "true" => Token::True,
+            "false" => Token::False,
+            _ => Token::Ident(ident),
+        }
+    }
+
+    fn read_string(&mut self, span: Span) -> Result<Token> {
+        self.bump(); // opening quote
+        let mut value = String::new();
+        loop {
+            match self.chars.peek() {
+                Some(&'"') => {
+                    self.bump();
+                    return Ok(Token::Str(value));
+                }
+                Some(&c) => {
+                    value.push(c);
+                    self.bump();
+                }
+                None => return Err(InterpretError::UnterminatedString { span }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens_of(src: &str) -> Vec<Token> {
+        Lexer::new(src)
+            .tokenize()
+            .unwrap()
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect()
+    }
+
+    #[test]
+    fn tokenizes_a_let_statement() {
+        assert_eq!(
+            tokens_of("let x = 1 + 2;"),
+            vec![
+                Token::Let,
+                Token::Ident("x".to_string()),
+                Token::Eq,
+                Token::Int(1),
+                Token::Plus,
+                Token::Int(2),
+                Token::Semicolon,
+                Token::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn distinguishes_eq_from_eqeq_and_lexes_noteq() {
+        assert_eq!(tokens_of("="), vec![Token::Eq, Token::Eof]);
+        assert_eq!(tokens_of("=="), vec![Token::EqEq, Token::Eof]);
+        assert_eq!(tokens_of("!="), vec![Token::NotEq, Token::Eof]);
+    }
+
+    #[test]
+    fn overflowing_integer_literal_is_an_invalid_number_error() {
+        let result = Lexer::new("99999999999999999999;").tokenize();
+        assert!(matches!(result, Err(InterpretError::InvalidNumber { .. })));
+    }
+
+    #[test]
+    fn unknown_character_is_an_unexpected_char_error() {
+        let result = Lexer::new("@").tokenize();
+        assert!(matches!(
+            result,
+            Err(InterpretError::UnexpectedChar { found: '@', .. })
+        ));
+    }
+
+    #[test]
+    fn lone_bang_is_an_unexpected_char_error() {
+        let result = Lexer::new("!").tokenize();
+        assert!(matches!(result, Err(InterpretError::UnexpectedChar { .. })));
+    }
+
+    #[test]
+    fn unterminated_string_is_an_error_at_the_opening_quote() {
+        let result = Lexer::new("let x = \"abc;\nprint(x);\n").tokenize();
+        assert!(matches!(
+            result,
+            Err(InterpretError::UnterminatedString {
+                span: Span { line: 1, col: 9 }
+            })
+        ));
+    }
+
+    #[test]
+    fn terminated_string_tokenizes_normally() {
+        assert_eq!(
+            tokens_of(r#""hi""#),
+            vec![Token::Str("hi".to_string()), Token::Eof]
+        );
+    }
+}
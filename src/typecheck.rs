@@ -0,0 +1,258 @@
+//! Static type-checking pass used by the `check` subcommand. Walks the AST
+//! the same way the interpreter does, but over `Type`s instead of `Value`s,
+//! so obviously-wrong programs (a condition that isn't a `Bool`, arithmetic
+//! on a `Str`, a call with the wrong number of arguments) are reported
+//! without running anything.
+//!
+//! Variables and function calls whose type can't be pinned down statically
+//! (e.g. a function's return type, since params carry no declared type)
+//! are treated as `Unknown` rather than rejected, so this catches clear
+//! mistakes without having to fully solve the program's types.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::ast::{BinOp, Expr, Statement};
+use crate::environment::Environment;
+use crate::error::{InterpretError, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Type {
+    Int,
+    Bool,
+    Str,
+    Unknown,
+}
+
+struct FunctionSig {
+    arity: usize,
+}
+
+pub struct Checker {
+    global: Rc<RefCell<Environment<Type>>>,
+    env: Rc<RefCell<Environment<Type>>>,
+    functions: HashMap<String, FunctionSig>,
+}
+
+impl Checker {
+    pub fn new() -> Self {
+        let global = Rc::new(RefCell::new(Environment::new()));
+        Checker {
+            env: Rc::clone(&global),
+            global,
+            functions: HashMap::new(),
+        }
+    }
+
+    pub fn check(&mut self, statements: &[Statement]) -> Result<()> {
+        for statement in statements {
+            self.check_statement(statement)?;
+        }
+        Ok(())
+    }
+
+    fn check_statement(&mut self, statement: &Statement) -> Result<()> {
+        match statement {
+            Statement::VarAssign { var, value } => {
+                let ty = self.check_expr(value)?;
+                self.env.borrow_mut().define(var.clone(), ty);
+            }
+            Statement::VarUpdate { var, value } => {
+                let ty = self.check_expr(value)?;
+                self.env.borrow_mut().set(var.clone(), ty);
+            }
+            Statement::IfCondition {
+                cond,
+                true_branch,
+                false_branch,
+            } => {
+                self.check_condition(cond)?;
+                self.check_block(true_branch)?;
+                self.check_block(false_branch)?;
+            }
+            Statement::WhileLoop { cond, body } => {
+                self.check_condition(cond)?;
+                self.check_block(body)?;
+            }
+            Statement::FunctionDef {
+                name,
+                params,
+                body,
+                ret,
+            } => {
+                self.functions.insert(
+                    name.clone(),
+                    FunctionSig {
+                        arity: params.len(),
+                    },
+                );
+
+                let call_env = Rc::new(RefCell::new(Environment::child(Rc::clone(&self.global))));
+                for param in params {
+                    call_env.borrow_mut().define(param.clone(), Type::Unknown);
+                }
+                let parent = std::mem::replace(&mut self.env, call_env);
+                let result = self.check(body).and_then(|()| match ret {
+                    Some(expr) => self.check_expr(expr).map(|_| ()),
+                    None => Ok(()),
+                });
+                self.env = parent;
+                result?;
+            }
+            Statement::FunctionCall { name, args } => {
+                self.check_call(name, args)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_block(&mut self, body: &[Statement]) -> Result<()> {
+        let parent = Rc::clone(&self.env);
+        self.env = Rc::new(RefCell::new(Environment::child(Rc::clone(&parent))));
+        let result = self.check(body);
+        self.env = parent;
+        result
+    }
+
+    fn check_condition(&mut self, cond: &Expr) -> Result<()> {
+        match self.check_expr(cond)? {
+            Type::Bool | Type::Unknown => Ok(()),
+            other => Err(InterpretError::TypeError {
+                message: format!("expected a boolean condition, found {}", other.describe()),
+            }),
+        }
+    }
+
+    fn check_expr(&mut self, expr: &Expr) -> Result<Type> {
+        match expr {
+            Expr::Int(_) => Ok(Type::Int),
+            Expr::Bool(_) => Ok(Type::Bool),
+            Expr::Str(_) => Ok(Type::Str),
+            Expr::Var(name) => Ok(self.env.borrow().get(name).unwrap_or(Type::Unknown)),
+            Expr::Binary { op, left, right } => {
+                let l = self.check_expr(left)?;
+                let r = self.check_expr(right)?;
+                check_binary(*op, l, r)
+            }
+            Expr::Call { name, args } => self.check_call(name, args),
+        }
+    }
+
+    fn check_call(&mut self, name: &str, args: &[Expr]) -> Result<Type> {
+        for arg in args {
+            self.check_expr(arg)?;
+        }
+        if name == "print" || name == "arg" {
+            return Ok(Type::Unknown);
+        }
+        match self.functions.get(name) {
+            Some(sig) if sig.arity == args.len() => Ok(Type::Unknown),
+            Some(sig) => Err(InterpretError::TypeError {
+                message: format!(
+                    "function `{}` expects {} argument(s), found {}",
+                    name,
+                    sig.arity,
+                    args.len()
+                ),
+            }),
+            None => Err(InterpretError::UnknownFunction {
+                name: name.to_string(),
+            }),
+        }
+    }
+}
+
+fn check_binary(op: BinOp, l: Type, r: Type) -> Result<Type> {
+    let numeric = |t: Type| matches!(t, Type::Int | Type::Unknown);
+    match op {
+        BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Mod => {
+            if numeric(l) && numeric(r) {
+                Ok(Type::Int)
+            } else {
+                Err(InterpretError::TypeError {
+                    message: format!(
+                        "expected integers on both sides of arithmetic, found {} and {}",
+                        l.describe(),
+                        r.describe()
+                    ),
+                })
+            }
+        }
+        BinOp::Eq | BinOp::NotEq => Ok(Type::Bool),
+        BinOp::Lt | BinOp::Gt | BinOp::Le | BinOp::Ge => {
+            if numeric(l) && numeric(r) {
+                Ok(Type::Bool)
+            } else {
+                Err(InterpretError::TypeError {
+                    message: format!(
+                        "expected integers on both sides of comparison, found {} and {}",
+                        l.describe(),
+                        r.describe()
+                    ),
+                })
+            }
+        }
+    }
+}
+
+impl Type {
+    fn describe(&self) -> &'static str {
+        match self {
+            Type::Int => "an integer",
+            Type::Bool => "a boolean",
+            Type::Str => "a string",
+            Type::Unknown => "an indeterminate type",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+    use crate::token::Lexer;
+
+    fn check_src(src: &str) -> Result<()> {
+        let tokens = Lexer::new(src).tokenize().unwrap();
+        let statements = Parser::new(tokens).parse_program().unwrap();
+        Checker::new().check(&statements)
+    }
+
+    #[test]
+    fn well_typed_program_passes() {
+        assert!(check_src("let x = 1 + 2; if x == 3 { print(x); }").is_ok());
+    }
+
+    #[test]
+    fn non_boolean_condition_is_a_type_error() {
+        assert!(matches!(
+            check_src("if 5 { }"),
+            Err(InterpretError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn mixing_int_and_str_in_arithmetic_is_a_type_error() {
+        assert!(matches!(
+            check_src(r#"let x = 1 + "a";"#),
+            Err(InterpretError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn calling_a_function_with_the_wrong_arity_is_a_type_error() {
+        assert!(matches!(
+            check_src("fn add(a, b) { return a + b; } add(1);"),
+            Err(InterpretError::TypeError { .. })
+        ));
+    }
+
+    #[test]
+    fn calling_an_unknown_function_is_an_unknown_function_error() {
+        assert!(matches!(
+            check_src("missing(1);"),
+            Err(InterpretError::UnknownFunction { .. })
+        ));
+    }
+}
@@ -0,0 +1,74 @@
+//! Runtime value type for the interpreter.
+
+use std::fmt;
+
+use crate::error::{InterpretError, Result};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Bool(bool),
+    Str(String),
+}
+
+impl Value {
+    pub fn as_int(&self) -> Result<i64> {
+        match self {
+            Value::Int(n) => Ok(*n),
+            other => Err(InterpretError::TypeError {
+                message: format!("expected an integer, found {}", other),
+            }),
+        }
+    }
+
+    pub fn as_bool(&self) -> Result<bool> {
+        match self {
+            Value::Bool(b) => Ok(*b),
+            other => Err(InterpretError::TypeError {
+                message: format!("expected a boolean, found {}", other),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_int_succeeds_for_int() {
+        assert_eq!(Value::Int(5).as_int().unwrap(), 5);
+    }
+
+    #[test]
+    fn as_int_errors_for_non_int() {
+        assert!(Value::Bool(true).as_int().is_err());
+    }
+
+    #[test]
+    fn as_bool_succeeds_for_bool() {
+        assert!(!Value::Bool(false).as_bool().unwrap());
+    }
+
+    #[test]
+    fn as_bool_errors_for_non_bool() {
+        assert!(Value::Str("x".to_string()).as_bool().is_err());
+    }
+
+    #[test]
+    fn display_formats_each_variant() {
+        assert_eq!(Value::Int(5).to_string(), "5");
+        assert_eq!(Value::Bool(true).to_string(), "true");
+        assert_eq!(Value::Str("hi".to_string()).to_string(), "hi");
+    }
+}